@@ -8,11 +8,42 @@ use super::websockets::WebsocketEvent;
 #[derive(Debug, Clone, Deserialize, Error)]
 #[error("code: {code}, msg: {msg}")]
 pub struct HuobiContentError {
-    pub code: i16,
+    pub code: i32,
     pub msg: String,
 
     #[serde(flatten)]
-    extra: HashMap<String, Value>,
+    pub(crate) extra: HashMap<String, Value>,
+}
+
+impl HuobiContentError {
+    /// Classify this content error into a specific `Error` variant using `code` and
+    /// `extra`, falling back to the flat `HuobiError` wrapper when the code isn't one
+    /// we recognize. Keeps the original `code`/`msg`/`extra` intact on the fallback so
+    /// nothing is lost for codes we don't yet special-case.
+    pub fn into_typed(self) -> Error {
+        match self.code {
+            // Unknown or de-listed trading symbol
+            1010 => {
+                let symbol = self
+                    .extra
+                    .get("symbol")
+                    .or_else(|| self.extra.get("symbol-partition"))
+                    .and_then(Value::as_str)
+                    .unwrap_or_default()
+                    .to_string();
+                Error::UnknownSymbol(symbol)
+            }
+            // Invalid order price
+            1016 => Error::InvalidPrice,
+            // Signature / auth failures
+            1001..=1099 => Error::Unauthorized,
+            // Temporarily overloaded / under maintenance
+            503 => Error::ServiceUnavailable,
+            // Everything else in the 5xx-equivalent range
+            500..=599 => Error::InternalServerError,
+            _ => Error::HuobiError { response: self },
+        }
+    }
 }
 
 /// First errors are technical errors
@@ -44,10 +75,7 @@ pub enum Error {
     #[error(transparent)]
     UTF8Err(#[from] std::str::Utf8Error),
     #[error("{response}")]
-    HuobiError {
-        #[from]
-        response: HuobiContentError,
-    },
+    HuobiError { response: HuobiContentError },
     #[error("invalid listen key : {0}")]
     InvalidListenKey(String),
     #[error("unknown symbol {0}")]
@@ -68,9 +96,87 @@ pub enum Error {
     Msg(String),
 }
 
+// Route every `?`-based conversion from a raw content error through `into_typed`
+// instead of always collapsing into the flat `HuobiError` variant. `okex_v5`'s
+// `classify_ack_error` (called from `WebSockets::complete_pending`) also builds a
+// `HuobiContentError` directly from ack fields to get the same classification on its own ack path (see there).
+impl From<HuobiContentError> for Error {
+    fn from(response: HuobiContentError) -> Self {
+        response.into_typed()
+    }
+}
+
 /// Custom error messages
 pub mod error_messages {
     pub const INVALID_PRICE: &str = "Invalid price.";
 }
 
 pub type Result<T> = core::result::Result<T, Error>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn content_error(code: i32, symbol: Option<&str>) -> HuobiContentError {
+        let mut extra = HashMap::new();
+        if let Some(symbol) = symbol {
+            extra.insert("symbol".to_string(), Value::String(symbol.to_string()));
+        }
+        HuobiContentError {
+            code,
+            msg: "boom".to_string(),
+            extra,
+        }
+    }
+
+    #[test]
+    fn classifies_auth_codes_as_unauthorized() {
+        assert!(matches!(
+            content_error(1050, None).into_typed(),
+            Error::Unauthorized
+        ));
+    }
+
+    #[test]
+    fn classifies_unknown_symbol_and_pulls_symbol_from_extra() {
+        match content_error(1010, Some("btcusdt")).into_typed() {
+            Error::UnknownSymbol(symbol) => assert_eq!(symbol, "btcusdt"),
+            other => panic!("expected UnknownSymbol, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn classifies_bad_price_code() {
+        assert!(matches!(content_error(1016, None).into_typed(), Error::InvalidPrice));
+    }
+
+    #[test]
+    fn classifies_5xx_range_as_internal_server_error() {
+        assert!(matches!(
+            content_error(500, None).into_typed(),
+            Error::InternalServerError
+        ));
+    }
+
+    #[test]
+    fn classifies_503_as_service_unavailable() {
+        assert!(matches!(
+            content_error(503, None).into_typed(),
+            Error::ServiceUnavailable
+        ));
+    }
+
+    #[test]
+    fn falls_back_to_huobi_error_for_unknown_codes() {
+        assert!(matches!(
+            content_error(9999, None).into_typed(),
+            Error::HuobiError { .. }
+        ));
+    }
+
+    #[test]
+    fn from_impl_routes_through_into_typed() {
+        let err: Error = content_error(1050, None).into();
+        assert!(matches!(err, Error::Unauthorized));
+    }
+}
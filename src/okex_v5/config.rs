@@ -0,0 +1,94 @@
+#[derive(Clone, Debug)]
+pub struct Config {
+    pub rest_api_endpoint: String,
+    pub ws_endpoint: String,
+    pub reconnect: ReconnectPolicy,
+    pub heartbeat: HeartbeatPolicy,
+}
+
+/// Governs the keepalive pings `WebSockets::event_loop` sends and how long it
+/// tolerates silence from the server before treating the connection as dead.
+#[derive(Clone, Debug)]
+pub struct HeartbeatPolicy {
+    /// How often to send an application-level `ping` frame.
+    pub ping_interval: std::time::Duration,
+    /// Treat the connection as dead if nothing is received for this long.
+    pub timeout: std::time::Duration,
+}
+
+impl Default for HeartbeatPolicy {
+    fn default() -> Self {
+        Self {
+            ping_interval: std::time::Duration::from_secs(20),
+            timeout: std::time::Duration::from_secs(30),
+        }
+    }
+}
+
+/// Governs how `WebSockets::event_loop` behaves when the connection drops.
+#[derive(Clone, Debug)]
+pub struct ReconnectPolicy {
+    /// Reconnect automatically instead of returning an error on disconnect.
+    pub enabled: bool,
+    /// Give up and propagate the error after this many consecutive failed attempts.
+    /// `None` means retry forever.
+    pub max_retries: Option<u32>,
+    /// Backoff before the first reconnect attempt.
+    pub base_delay: std::time::Duration,
+    /// Upper bound the exponential backoff is capped at.
+    pub max_delay: std::time::Duration,
+}
+
+impl Default for ReconnectPolicy {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            max_retries: None,
+            base_delay: std::time::Duration::from_millis(500),
+            max_delay: std::time::Duration::from_secs(30),
+        }
+    }
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            rest_api_endpoint: "https://www.okx.com".into(),
+            ws_endpoint: "wss://ws.okx.com:8443/ws/v5".into(),
+            reconnect: ReconnectPolicy::default(),
+            heartbeat: HeartbeatPolicy::default(),
+        }
+    }
+}
+
+impl Config {
+    /// Configure against OKX's demo trading environment.
+    pub fn demo_trading() -> Self {
+        Self {
+            rest_api_endpoint: "https://www.okx.com".into(),
+            ws_endpoint: "wss://wspap.okx.com:8443/ws/v5".into(),
+            reconnect: ReconnectPolicy::default(),
+            heartbeat: HeartbeatPolicy::default(),
+        }
+    }
+
+    pub fn set_rest_api_endpoint(mut self, rest_api_endpoint: impl Into<String>) -> Self {
+        self.rest_api_endpoint = rest_api_endpoint.into();
+        self
+    }
+
+    pub fn set_ws_endpoint(mut self, ws_endpoint: impl Into<String>) -> Self {
+        self.ws_endpoint = ws_endpoint.into();
+        self
+    }
+
+    pub fn set_reconnect(mut self, reconnect: ReconnectPolicy) -> Self {
+        self.reconnect = reconnect;
+        self
+    }
+
+    pub fn set_heartbeat(mut self, heartbeat: HeartbeatPolicy) -> Self {
+        self.heartbeat = heartbeat;
+        self
+    }
+}
@@ -2,26 +2,114 @@ use super::config::*;
 use super::errors::*;
 use super::rest_model::OrderType;
 use super::ws_model::WebsocketResponse;
+use crate::huobi::errors::HuobiContentError;
 
 use awc::ws::Message;
 use log::debug;
+use std::collections::{BTreeMap, HashMap};
 use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
 
 use actix_codec::Framed;
 use awc::{
     ws::{Codec, Frame},
     BoxedSocket, Client, ClientResponse,
 };
+use bytes::Bytes;
 use futures_util::{sink::SinkExt as _, stream::StreamExt as _};
 use serde::{Deserialize, Serialize};
-use serde_json::from_slice;
-use tokio::sync::mpsc;
+use serde_json::{from_slice, Value};
+use std::time::Instant;
+use tokio::sync::{mpsc, oneshot};
+use tokio::time::timeout;
 use uuid::Uuid;
 
+/// A pending request awaiting an ack from the exchange, keyed by the request `id`
+/// that was sent out on `WSOrderRequest`.
+type PendingRequests = Arc<Mutex<BTreeMap<String, oneshot::Sender<Result<Value>>>>>;
+
+/// Decodes and forwards a single multiplexed push message to its typed subscriber,
+/// dropping it silently if the payload no longer matches `T`.
+type ChannelRoute = Box<dyn Fn(Value) + Send + Sync>;
+type ChannelRouter = Arc<Mutex<HashMap<String, ChannelRoute>>>;
+
+/// The `arg` object OKX stamps on every channel push, identifying which
+/// subscription a message belongs to.
+#[derive(Debug, Deserialize)]
+struct ChannelArg {
+    channel: String,
+    #[serde(rename = "instId", default)]
+    inst_id: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct MultiplexEnvelope {
+    arg: ChannelArg,
+    #[serde(default)]
+    data: Vec<Value>,
+}
+
+fn channel_key(channel: &str, inst_id: Option<&str>) -> String {
+    match inst_id {
+        Some(inst_id) => format!("{}:{}", channel, inst_id),
+        None => channel.to_string(),
+    }
+}
+
+/// Minimal shape of an order-channel ack frame, shared by `order`, `cancel-order`,
+/// `amend-order` and `batch-*` ops.
+#[derive(Debug, Deserialize)]
+struct WSAck {
+    id: String,
+    // OKX sends `code` as a JSON string (e.g. `"0"`, `"51000"`), never a number.
+    code: String,
+    #[serde(default)]
+    msg: Option<String>,
+    #[serde(default)]
+    data: Option<Value>,
+}
+
+/// OKX's WS heartbeat is a bare text frame, not JSON: the client sends the literal
+/// string `"ping"` and the server answers with the literal string `"pong"`.
+const HEARTBEAT_PING: &str = "ping";
+const HEARTBEAT_PONG: &str = "pong";
+
+/// Classify a failed ack's `code`/`msg` the same way `huobi::errors` classifies a
+/// `HuobiContentError`, instead of flattening every non-zero code into the same
+/// generic message. `okex_v5` doesn't have its own content-error taxonomy, and
+/// OKX's and Huobi's numeric code ranges line up closely enough (auth, bad
+/// symbol/price, server overload) that reusing `HuobiContentError::into_typed`
+/// here is more useful than inventing a parallel one-off classifier.
+fn classify_ack_error(code: &str, msg: Option<&str>) -> crate::huobi::errors::Error {
+    HuobiContentError {
+        code: code.parse().unwrap_or_default(),
+        msg: msg.unwrap_or_default().to_string(),
+        extra: HashMap::new(),
+    }
+    .into_typed()
+}
+
 pub struct WebSockets<WE: serde::de::DeserializeOwned> {
     pub socket: Option<(ClientResponse, Framed<BoxedSocket, Codec>)>,
     sender: mpsc::Sender<WE>,
     conf: Config,
+    pending: PendingRequests,
+    /// Endpoint passed to the last successful `connect`, kept around so `event_loop`
+    /// can transparently reconnect without the caller having to redial.
+    endpoint: Option<String>,
+    /// Raw subscription frames passed to `subscribe_request`, replayed in order
+    /// against the new socket after a reconnect.
+    subscriptions: Vec<String>,
+    /// When the last frame of any kind was received, used to detect an idle connection.
+    last_seen: Instant,
+    /// When a keepalive ping was last sent.
+    last_ping_sent: Instant,
+    /// Per-subscription routes registered via `subscribe_typed`, keyed by
+    /// `channel_key(channel, inst_id)`.
+    routes: ChannelRouter,
+    /// Notified once per successful reconnect, so consumers (e.g. order book
+    /// trackers) know to resync instead of assuming a continuous stream.
+    reconnect_notify: Option<mpsc::Sender<()>>,
 }
 
 impl<WE: serde::de::DeserializeOwned> WebSockets<WE> {
@@ -40,9 +128,27 @@ impl<WE: serde::de::DeserializeOwned> WebSockets<WE> {
             socket: None,
             sender: sender,
             conf,
+            pending: Arc::new(Mutex::new(BTreeMap::new())),
+            endpoint: None,
+            subscriptions: Vec::new(),
+            last_seen: Instant::now(),
+            last_ping_sent: Instant::now(),
+            routes: Arc::new(Mutex::new(HashMap::new())),
+            reconnect_notify: None,
         }
     }
 
+    /// Subscribe to reconnect notifications: the returned receiver gets a `()`
+    /// when `event_loop` transparently re-establishes the connection, so
+    /// consumers know any streamed state (e.g. an order book) needs resyncing.
+    /// The channel holds one notification; a reconnect while one is still
+    /// unread adds no second one.
+    pub fn reconnect_notifications(&mut self) -> mpsc::Receiver<()> {
+        let (tx, rx) = mpsc::channel(1);
+        self.reconnect_notify = Some(tx);
+        rx
+    }
+
     /// Connect to a websocket endpoint
     pub async fn connect(&mut self, endpoint: &str) -> Result<()> {
         let wss: String = format!("{}/{}", self.conf.ws_endpoint, endpoint);
@@ -54,6 +160,12 @@ impl<WE: serde::de::DeserializeOwned> WebSockets<WE> {
         match client.ws(wss).connect().await {
             Ok(answer) => {
                 self.socket = Some(answer);
+                self.endpoint = Some(endpoint.to_string());
+                // A fresh socket hasn't gone idle and hasn't been pinged yet; without
+                // this, event_loop's heartbeat check uses the stale pre-reconnect
+                // instants and immediately decides the brand-new connection is dead.
+                self.last_seen = Instant::now();
+                self.last_ping_sent = Instant::now();
                 Ok(())
             }
             Err(e) => Err(Error::Msg(format!("Error during handshake {}", e))),
@@ -61,6 +173,41 @@ impl<WE: serde::de::DeserializeOwned> WebSockets<WE> {
     }
 
     pub async fn subscribe_request(&mut self, request: &str) -> Result<()> {
+        self.send_raw(request).await?;
+        self.subscriptions.push(request.to_string());
+        Ok(())
+    }
+
+    /// Subscribe to a channel and route its pushes to a dedicated typed receiver
+    /// instead of the single catch-all `sender`, so one connection can fan out many
+    /// symbols/channels without callers racing each other over a shared `WE`.
+    /// `channel`/`inst_id` must match the `arg.channel`/`arg.instId` OKX stamps on
+    /// pushes for this subscription.
+    pub async fn subscribe_typed<T>(
+        &mut self,
+        request: &str,
+        channel: impl Into<String>,
+        inst_id: Option<String>,
+    ) -> Result<mpsc::Receiver<T>>
+    where
+        T: serde::de::DeserializeOwned + Send + 'static,
+    {
+        let (tx, rx) = mpsc::channel(100);
+        let key = channel_key(&channel.into(), inst_id.as_deref());
+        self.routes.lock().unwrap().insert(
+            key,
+            Box::new(move |value: Value| {
+                if let Ok(typed) = serde_json::from_value::<T>(value) {
+                    let _ = tx.try_send(typed);
+                }
+            }),
+        );
+        self.subscribe_request(request).await?;
+        Ok(rx)
+    }
+
+    /// Send a raw frame without recording it as a subscription to replay later.
+    async fn send_raw(&mut self, request: &str) -> Result<()> {
         if let Some((_, ref mut socket)) = self.socket {
             socket.send(Message::Text(request.into())).await?;
             Ok(())
@@ -69,6 +216,63 @@ impl<WE: serde::de::DeserializeOwned> WebSockets<WE> {
         }
     }
 
+    /// Re-establish the connection and replay every subscription recorded via
+    /// `subscribe_request`, following `Config::reconnect`. Returns `Ok(true)` once
+    /// reconnected, `Ok(false)` if reconnection is disabled (the caller should
+    /// propagate the original disconnect error), and `Err` once retries are exhausted.
+    async fn try_reconnect(&mut self, running: &AtomicBool) -> Result<bool> {
+        if !self.conf.reconnect.enabled {
+            return Ok(false);
+        }
+        let endpoint = match self.endpoint.clone() {
+            Some(endpoint) => endpoint,
+            None => return Ok(false),
+        };
+
+        let mut attempt: u32 = 0;
+        let mut delay = self.conf.reconnect.base_delay;
+        loop {
+            if !running.load(Ordering::Relaxed) {
+                return Err(Error::Msg("reconnect aborted: shutting down".to_string()));
+            }
+            if let Some(max_retries) = self.conf.reconnect.max_retries {
+                if attempt >= max_retries {
+                    return Err(Error::Msg("exhausted reconnect attempts".to_string()));
+                }
+            }
+            attempt += 1;
+            actix_rt::time::sleep(delay).await;
+
+            match self.connect(&endpoint).await {
+                Ok(()) => {
+                    let mut replayed = true;
+                    for subscription in self.subscriptions.clone() {
+                        if self.send_raw(&subscription).await.is_err() {
+                            replayed = false;
+                            break;
+                        }
+                    }
+                    if !replayed {
+                        // The socket died again before we finished replaying
+                        // subscriptions; treat it like any other failed connect
+                        // attempt and keep retrying instead of unwinding out of
+                        // the reconnect loop.
+                        delay = std::cmp::min(delay * 2, self.conf.reconnect.max_delay);
+                        continue;
+                    }
+                    println!("Reconnected to {} after {} attempt(s)", endpoint, attempt);
+                    if let Some(notify) = &self.reconnect_notify {
+                        let _ = notify.try_send(());
+                    }
+                    return Ok(true);
+                }
+                Err(_e) => {
+                    delay = std::cmp::min(delay * 2, self.conf.reconnect.max_delay);
+                }
+            }
+        }
+    }
+
     /// Disconnect from the endpoint
     pub async fn disconnect(&mut self) -> Result<()> {
         if let Some((_, ref mut socket)) = self.socket {
@@ -83,16 +287,115 @@ impl<WE: serde::de::DeserializeOwned> WebSockets<WE> {
         &self.socket
     }
 
+    /// Fail every pending request and attempt the same transparent reconnect
+    /// every disconnect path in `event_loop` uses, whether the disconnect was
+    /// noticed on the read side (poll error, idle timeout, `Frame::Close`) or
+    /// the write side (a ping/pong send failing on a socket that's already dead).
+    async fn recover_from_disconnect(&mut self, running: &AtomicBool) -> Result<bool> {
+        self.fail_all_pending("connection closed");
+        self.try_reconnect(running).await
+    }
+
     pub async fn event_loop(&mut self, running: &AtomicBool) -> Result<()> {
         while running.load(Ordering::Relaxed) {
-            if let Some((_, ref mut socket)) = self.socket {
-                let message = socket.next().await.unwrap()?;
+            if self.socket.is_none() {
+                continue;
+            }
+
+            if self.last_ping_sent.elapsed() >= self.conf.heartbeat.ping_interval {
+                if let Err(_e) = self.send_heartbeat_ping().await {
+                    if self.recover_from_disconnect(running).await? {
+                        continue;
+                    }
+                    return Err(Error::Msg("connection closed while sending heartbeat ping".to_string()));
+                }
+            }
+
+            let remaining = self
+                .conf
+                .heartbeat
+                .timeout
+                .saturating_sub(self.last_seen.elapsed());
+            if remaining.is_zero() {
+                if self.recover_from_disconnect(running).await? {
+                    continue;
+                }
+                return Err(Error::Msg("heartbeat timeout".to_string()));
+            }
+
+            let polled = match self.socket.as_mut() {
+                Some((_, socket)) => match timeout(remaining, socket.next()).await {
+                    Ok(polled) => polled.unwrap(),
+                    Err(_elapsed) => {
+                        if self.recover_from_disconnect(running).await? {
+                            continue;
+                        }
+                        return Err(Error::Msg("heartbeat timeout".to_string()));
+                    }
+                },
+                None => continue,
+            };
+
+            let message = match polled {
+                Ok(message) => message,
+                Err(e) => {
+                    if self.recover_from_disconnect(running).await? {
+                        continue;
+                    }
+                    return Err(e.into());
+                }
+            };
+
+            self.last_seen = Instant::now();
+
+            {
                 debug!("event_loop message - {:?}", message);
                 match message {
                     Frame::Text(msg) => {
                         if msg.is_empty() {
                             return Ok(());
                         }
+                        if msg == HEARTBEAT_PING {
+                            if let Err(_e) = self.send_raw(HEARTBEAT_PONG).await {
+                                if self.recover_from_disconnect(running).await? {
+                                    continue;
+                                }
+                                return Err(Error::Msg(
+                                    "connection closed while replying to heartbeat ping".to_string(),
+                                ));
+                            }
+                            actix_rt::task::yield_now().await;
+                            continue;
+                        }
+                        if msg == HEARTBEAT_PONG {
+                            // Reply to our own keepalive; `last_seen` was already bumped above.
+                            actix_rt::task::yield_now().await;
+                            continue;
+                        }
+                        if let Ok(ack) = from_slice::<WSAck>(&msg) {
+                            if self.complete_pending(ack) {
+                                actix_rt::task::yield_now().await;
+                                continue;
+                            }
+                        }
+                        if let Ok(envelope) = from_slice::<MultiplexEnvelope>(&msg) {
+                            let key = channel_key(&envelope.arg.channel, envelope.arg.inst_id.as_deref());
+                            let routed = {
+                                let routes = self.routes.lock().unwrap();
+                                if let Some(route) = routes.get(&key) {
+                                    for item in envelope.data {
+                                        route(item);
+                                    }
+                                    true
+                                } else {
+                                    false
+                                }
+                            };
+                            if routed {
+                                actix_rt::task::yield_now().await;
+                                continue;
+                            }
+                        }
                         if let Ok(event) = from_slice(&msg) {
                             if let Err(_e) = self.sender.send(event).await {
                                 println!("SendError<WE>");
@@ -103,58 +406,115 @@ impl<WE: serde::de::DeserializeOwned> WebSockets<WE> {
                             return Err(Error::Msg(format!("Websocket Parse failed {:?}", msg)));
                         }
                     }
-                    Frame::Ping(_) | Frame::Pong(_) | Frame::Binary(_) | Frame::Continuation(_) => {
+                    Frame::Ping(data) => {
+                        if let Err(_e) = self.send_pong(data).await {
+                            if self.recover_from_disconnect(running).await? {
+                                continue;
+                            }
+                            return Err(Error::Msg("connection closed while replying to ping".to_string()));
+                        }
                     }
+                    Frame::Pong(_) | Frame::Binary(_) | Frame::Continuation(_) => {}
                     Frame::Close(e) => {
+                        if self.recover_from_disconnect(running).await? {
+                            continue;
+                        }
                         return Err(Error::Msg(format!("Disconnected {:?}", e)));
                     }
                 }
                 actix_rt::task::yield_now().await;
             }
         }
+        self.fail_all_pending("connection closed");
         Ok(())
     }
 
-    // trade start from here
-    async fn place_order(&mut self, order: WSOrder) -> Result<()> {
+    /// Answer a protocol-level `Frame::Ping` so the exchange doesn't drop us for silence.
+    async fn send_pong(&mut self, data: Bytes) -> Result<()> {
         if let Some((_, ref mut socket)) = self.socket {
-            let ws_order = WSOrderRequest {
-                id: Uuid::new_v4().to_string(),
-                op: "order".to_string(),
-                args: vec![order],
-            };
+            socket.send(Message::Pong(data)).await?;
+        }
+        Ok(())
+    }
 
-            let text = serde_json::to_string(&ws_order)?;
-            socket.send(Message::Text(text.into())).await?;
-            Ok(())
+    /// Send the application-level keepalive ping and reset the interval clock.
+    async fn send_heartbeat_ping(&mut self) -> Result<()> {
+        self.last_ping_sent = Instant::now();
+        if self.socket.is_none() {
+            return Ok(());
+        }
+        self.send_raw(HEARTBEAT_PING).await
+    }
+
+    /// Resolve the pending request matching `ack.id`, if any is outstanding.
+    /// Returns `true` if the frame was consumed as an ack and should not be
+    /// forwarded to the generic event stream.
+    fn complete_pending(&self, ack: WSAck) -> bool {
+        let tx = match self.pending.lock().unwrap().remove(&ack.id) {
+            Some(tx) => tx,
+            None => return false,
+        };
+        let result = if ack.code == "0" {
+            Ok(ack.data.unwrap_or(Value::Null))
         } else {
-            Err(Error::Msg("Not able to send requests".to_string()))
+            Err(Error::Msg(classify_ack_error(&ack.code, ack.msg.as_deref()).to_string()))
+        };
+        let _ = tx.send(result);
+        true
+    }
+
+    /// Fail every outstanding request so callers awaiting an ack don't hang forever
+    /// once the connection is gone.
+    fn fail_all_pending(&self, reason: &str) {
+        let mut pending = self.pending.lock().unwrap();
+        for (_, tx) in std::mem::take(&mut *pending) {
+            let _ = tx.send(Err(Error::Msg(reason.to_string())));
         }
     }
 
-    async fn place_multipy_order(&mut self, orders: Vec<WSOrder>) -> Result<()> {
+    // trade start from here
+    /// Send a `WSOrderRequest`-shaped payload and register a pending request under
+    /// its `id` so the caller can await the exchange's ack instead of firing blind.
+    async fn send_request(&mut self, id: String, text: String) -> Result<oneshot::Receiver<Result<Value>>> {
         if let Some((_, ref mut socket)) = self.socket {
-            let ws_orders = WSOrderRequest {
-                id: Uuid::new_v4().to_string(),
-                op: "batch-orders".to_string(),
-                args: orders,
-            };
-
-            let text = serde_json::to_string(&ws_orders)?;
+            let (tx, rx) = oneshot::channel();
+            self.pending.lock().unwrap().insert(id, tx);
             socket.send(Message::Text(text.into())).await?;
-            Ok(())
+            Ok(rx)
         } else {
             Err(Error::Msg("Not able to send requests".to_string()))
         }
     }
 
+    async fn place_order(&mut self, order: WSOrder) -> Result<oneshot::Receiver<Result<Value>>> {
+        let id = Uuid::new_v4().to_string();
+        let ws_order = WSOrderRequest {
+            id: id.clone(),
+            op: "order".to_string(),
+            args: vec![order],
+        };
+        let text = serde_json::to_string(&ws_order)?;
+        self.send_request(id, text).await
+    }
+
+    async fn place_multipy_order(&mut self, orders: Vec<WSOrder>) -> Result<oneshot::Receiver<Result<Value>>> {
+        let id = Uuid::new_v4().to_string();
+        let ws_orders = WSOrderRequest {
+            id: id.clone(),
+            op: "batch-orders".to_string(),
+            args: orders,
+        };
+        let text = serde_json::to_string(&ws_orders)?;
+        self.send_request(id, text).await
+    }
+
     pub async fn limit_buy(
         &mut self,
         symbol: impl Into<String>,
         qty: impl Into<String>,
         price: impl Into<String>,
         order_type: OrderType,
-    ) -> Result<()> {
+    ) -> Result<oneshot::Receiver<Result<Value>>> {
         let order = WSOrder {
             symbol: symbol.into(),
             trade_mode: TradeMode::Cross,
@@ -169,8 +529,7 @@ impl<WE: serde::de::DeserializeOwned> WebSockets<WE> {
             reduce_only: None,
             target_currency: None,
         };
-        self.place_order(order).await?;
-        Ok(())
+        self.place_order(order).await
     }
 
     pub async fn limit_sell(
@@ -179,7 +538,7 @@ impl<WE: serde::de::DeserializeOwned> WebSockets<WE> {
         qty: impl Into<String>,
         price: impl Into<String>,
         order_type: OrderType,
-    ) -> Result<()> {
+    ) -> Result<oneshot::Receiver<Result<Value>>> {
         let order = WSOrder {
             symbol: symbol.into(),
             trade_mode: TradeMode::Cross,
@@ -194,19 +553,112 @@ impl<WE: serde::de::DeserializeOwned> WebSockets<WE> {
             reduce_only: None,
             target_currency: None,
         };
-        self.place_order(order).await?;
-        Ok(())
+        self.place_order(order).await
     }
 
-    pub async fn market_buy() {}
+    pub async fn market_buy(
+        &mut self,
+        symbol: impl Into<String>,
+        qty: impl Into<String>,
+        target_currency: Option<String>,
+    ) -> Result<oneshot::Receiver<Result<Value>>> {
+        let order = WSOrder {
+            symbol: symbol.into(),
+            trade_mode: TradeMode::Cross,
+            currency: None,
+            client_order_id: None,
+            tag: None,
+            side: OrderSide::Buy,
+            position_side: None, // None for net mode
+            order_type: OrderType::Market,
+            qty: qty.into(),
+            price: None,
+            reduce_only: None,
+            target_currency,
+        };
+        self.place_order(order).await
+    }
 
-    pub async fn market_sell() {}
+    pub async fn market_sell(
+        &mut self,
+        symbol: impl Into<String>,
+        qty: impl Into<String>,
+        target_currency: Option<String>,
+    ) -> Result<oneshot::Receiver<Result<Value>>> {
+        let order = WSOrder {
+            symbol: symbol.into(),
+            trade_mode: TradeMode::Cross,
+            currency: None,
+            client_order_id: None,
+            tag: None,
+            side: OrderSide::Sell,
+            position_side: None, // None for net mode
+            order_type: OrderType::Market,
+            qty: qty.into(),
+            price: None,
+            reduce_only: None,
+            target_currency,
+        };
+        self.place_order(order).await
+    }
 
-    pub async fn cancel_order() {}
+    pub async fn cancel_order(
+        &mut self,
+        symbol: impl Into<String>,
+        order_id: Option<String>,
+        client_order_id: Option<String>,
+    ) -> Result<oneshot::Receiver<Result<Value>>> {
+        let id = Uuid::new_v4().to_string();
+        let request = WSCancelOrderRequest {
+            id: id.clone(),
+            op: "cancel-order".to_string(),
+            args: vec![WSCancelOrder {
+                symbol: symbol.into(),
+                order_id,
+                client_order_id,
+            }],
+        };
+        let text = serde_json::to_string(&request)?;
+        self.send_request(id, text).await
+    }
 
-    pub async fn amend_order() {}
+    pub async fn amend_order(
+        &mut self,
+        symbol: impl Into<String>,
+        order_id: Option<String>,
+        client_order_id: Option<String>,
+        new_qty: Option<String>,
+        new_price: Option<String>,
+    ) -> Result<oneshot::Receiver<Result<Value>>> {
+        let id = Uuid::new_v4().to_string();
+        let request = WSAmendOrderRequest {
+            id: id.clone(),
+            op: "amend-order".to_string(),
+            args: vec![WSAmendOrder {
+                symbol: symbol.into(),
+                order_id,
+                client_order_id,
+                new_qty,
+                new_price,
+            }],
+        };
+        let text = serde_json::to_string(&request)?;
+        self.send_request(id, text).await
+    }
 
-    pub async fn amend_multiple_order() {}
+    pub async fn amend_multiple_order(
+        &mut self,
+        amendments: Vec<WSAmendOrder>,
+    ) -> Result<oneshot::Receiver<Result<Value>>> {
+        let id = Uuid::new_v4().to_string();
+        let request = WSAmendOrderRequest {
+            id: id.clone(),
+            op: "batch-amend-orders".to_string(),
+            args: amendments,
+        };
+        let text = serde_json::to_string(&request)?;
+        self.send_request(id, text).await
+    }
 }
 
 #[derive(Deserialize, Serialize)]
@@ -278,3 +730,113 @@ pub struct WSOrder {
     #[serde(rename = "tgtCcy")]
     pub target_currency: Option<String>,
 }
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WSCancelOrderRequest {
+    pub id: String,
+    pub op: String,
+    pub args: Vec<WSCancelOrder>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WSCancelOrder {
+    #[serde(rename = "inst_id")]
+    pub symbol: String,
+    #[serde(rename = "ordId", skip_serializing_if = "Option::is_none")]
+    pub order_id: Option<String>,
+    #[serde(rename = "clOrdId", skip_serializing_if = "Option::is_none")]
+    pub client_order_id: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WSAmendOrderRequest {
+    pub id: String,
+    pub op: String,
+    pub args: Vec<WSAmendOrder>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WSAmendOrder {
+    #[serde(rename = "inst_id")]
+    pub symbol: String,
+    #[serde(rename = "ordId", skip_serializing_if = "Option::is_none")]
+    pub order_id: Option<String>,
+    #[serde(rename = "clOrdId", skip_serializing_if = "Option::is_none")]
+    pub client_order_id: Option<String>,
+    #[serde(rename = "newSz", skip_serializing_if = "Option::is_none")]
+    pub new_qty: Option<String>,
+    #[serde(rename = "newPx", skip_serializing_if = "Option::is_none")]
+    pub new_price: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn complete_pending_resolves_success_ack_with_string_code() {
+        let (sender, _rx) = mpsc::channel::<Value>(1);
+        let ws: WebSockets<Value> = WebSockets::new(sender);
+        let (tx, rx) = oneshot::channel();
+        ws.pending.lock().unwrap().insert("req-1".to_string(), tx);
+
+        let ack = WSAck {
+            id: "req-1".to_string(),
+            code: "0".to_string(),
+            msg: None,
+            data: Some(Value::String("ok".to_string())),
+        };
+        assert!(ws.complete_pending(ack));
+        assert_eq!(rx.await.unwrap().unwrap(), Value::String("ok".to_string()));
+    }
+
+    #[tokio::test]
+    async fn complete_pending_resolves_error_ack_with_nonzero_string_code() {
+        let (sender, _rx) = mpsc::channel::<Value>(1);
+        let ws: WebSockets<Value> = WebSockets::new(sender);
+        let (tx, rx) = oneshot::channel();
+        ws.pending.lock().unwrap().insert("req-2".to_string(), tx);
+
+        let ack = WSAck {
+            id: "req-2".to_string(),
+            code: "51000".to_string(),
+            msg: Some("Parameter error".to_string()),
+            data: None,
+        };
+        assert!(ws.complete_pending(ack));
+        assert!(rx.await.unwrap().is_err());
+    }
+
+    #[test]
+    fn channel_key_combines_channel_and_inst_id() {
+        assert_eq!(channel_key("trades", Some("BTC-USDT")), "trades:BTC-USDT");
+    }
+
+    #[test]
+    fn channel_key_falls_back_to_bare_channel_without_inst_id() {
+        assert_eq!(channel_key("account", None), "account");
+    }
+
+    #[test]
+    fn classify_ack_error_uses_huobi_content_error_classification() {
+        assert!(matches!(
+            classify_ack_error("1050", None),
+            crate::huobi::errors::Error::Unauthorized
+        ));
+    }
+
+    #[test]
+    fn complete_pending_ignores_unknown_id() {
+        let (sender, _rx) = mpsc::channel::<Value>(1);
+        let ws: WebSockets<Value> = WebSockets::new(sender);
+        let ack = WSAck {
+            id: "missing".to_string(),
+            code: "0".to_string(),
+            msg: None,
+            data: None,
+        };
+        assert!(!ws.complete_pending(ack));
+    }
+}